@@ -76,6 +76,85 @@ with_std! {
             let failure = Either::That(error.into());
             Context { context, failure }
         }
+
+        /// Returns an iterator over the causes of this context, starting with the
+        /// underlying error and following each `Fail::cause` down the chain.
+        pub fn iter_causes(&self) -> Causes {
+            Causes { fail: self.failure.cause() }
+        }
+
+        /// Returns the innermost cause of this context, walking the chain to its
+        /// last link. Returns `None` when the context has no underlying error.
+        pub fn root_cause(&self) -> Option<&Fail> {
+            let mut cause = self.failure.cause()?;
+            while let Some(next) = cause.cause() {
+                cause = next;
+            }
+            Some(cause)
+        }
+
+        /// Attempt to downcast the underlying error to a concrete type by reference.
+        ///
+        /// Returns `None` when the context carries no underlying error (the
+        /// backtrace-only variant) or when the error is not of type `T`.
+        pub fn downcast_ref<T: Fail>(&self) -> Option<&T> {
+            match self.failure {
+                Either::This(_)            => None,
+                Either::That(ref error)    => error.downcast_ref(),
+                Either::Both(_, ref error) => error.downcast_ref(),
+            }
+        }
+
+        /// Attempt to downcast the underlying error to a concrete type by mutable
+        /// reference.
+        ///
+        /// Returns `None` when the context carries no underlying error (the
+        /// backtrace-only variant) or when the error is not of type `T`.
+        pub fn downcast_mut<T: Fail>(&mut self) -> Option<&mut T> {
+            match self.failure {
+                Either::This(_)             => None,
+                Either::That(ref mut error) => error.downcast_mut(),
+                Either::Both(_, ref mut error) => error.downcast_mut(),
+            }
+        }
+
+        /// Replace the captured backtrace with one taken at the current callsite,
+        /// keeping the context and underlying cause intact.
+        ///
+        /// This is useful when a `Context` crosses a thread boundary: the embedded
+        /// backtrace points into the stack where the error was produced, which is
+        /// usually not the interesting site once it has been received elsewhere.
+        pub fn with_fresh_backtrace(self) -> Context<D> {
+            self.with_backtrace(Backtrace::new())
+        }
+
+        /// Replace the captured backtrace with an explicit one, keeping the context
+        /// and underlying cause intact.
+        pub fn with_backtrace(self, backtrace: Backtrace) -> Context<D> {
+            let Context { context, failure } = self;
+            let failure = match failure {
+                Either::This(_)            => Either::This(backtrace),
+                Either::That(error)        => Either::Both(backtrace, error),
+                Either::Both(_, error)     => Either::Both(backtrace, error),
+            };
+            Context { context, failure }
+        }
+    }
+
+    /// An iterator over the causes of a `Context`, returned by
+    /// [`Context::iter_causes`](struct.Context.html#method.iter_causes).
+    pub struct Causes<'a> {
+        fail: Option<&'a Fail>,
+    }
+
+    impl<'a> Iterator for Causes<'a> {
+        type Item = &'a Fail;
+
+        fn next(&mut self) -> Option<&'a Fail> {
+            let fail = self.fail?;
+            self.fail = fail.cause();
+            Some(fail)
+        }
     }
 
     impl<D: Display + Send + Sync + 'static> Fail for Context<D> {
@@ -90,33 +169,71 @@ with_std! {
 
     impl<D: Display + Send + Sync + 'static> Debug for Context<D> {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "{:?}\n\n{}", self.failure, self.context)
+            write!(f, "{}", self.context)?;
+
+            let mut cause = self.failure.cause();
+            if cause.is_some() {
+                write!(f, "\n\nCaused by:")?;
+                let mut index = 0;
+                while let Some(fail) = cause {
+                    let msg = fail.to_string();
+                    let mut lines = msg.lines();
+                    if let Some(first) = lines.next() {
+                        write!(f, "\n{:>5}: {}", index, first)?;
+                        for line in lines {
+                            write!(f, "\n       {}", line)?;
+                        }
+                    } else {
+                        write!(f, "\n{:>5}: ", index)?;
+                    }
+                    index += 1;
+                    cause = fail.cause();
+                }
+            }
+
+            let backtrace = self.failure.backtrace();
+            if !backtrace.is_empty() {
+                write!(f, "\n\nStack backtrace:\n{}", backtrace)?;
+            }
+
+            Ok(())
         }
     }
 
     impl<D: Display + Send + Sync + 'static> Display for Context<D> {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            write!(f, "{}", self.context)
+            write!(f, "{}", self.context)?;
+            if f.alternate() {
+                let mut cause = self.failure.cause();
+                while let Some(fail) = cause {
+                    write!(f, ": {}", fail)?;
+                    cause = fail.cause();
+                }
+            }
+            Ok(())
         }
     }
 
     enum Either<A, B> {
         This(A),
         That(B),
+        Both(A, B),
     }
 
     impl Either<Backtrace, Error> {
         fn backtrace(&self) -> &Backtrace {
             match *self {
-                Either::This(ref backtrace) => backtrace,
-                Either::That(ref error)     => error.backtrace(),
+                Either::This(ref backtrace)    => backtrace,
+                Either::That(ref error)        => error.backtrace(),
+                Either::Both(ref backtrace, _) => backtrace,
             }
         }
 
         fn cause(&self) -> Option<&Fail> {
             match *self {
-                Either::This(_)         => None,
-                Either::That(ref error) => Some(error.cause())
+                Either::This(_)             => None,
+                Either::That(ref error)     => Some(error.cause()),
+                Either::Both(_, ref error)  => Some(error.cause()),
             }
         }
     }
@@ -124,8 +241,9 @@ with_std! {
     impl Debug for Either<Backtrace, Error> {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             match *self {
-                Either::This(ref backtrace) => write!(f, "{:?}", backtrace),
-                Either::That(ref error)     => write!(f, "{:?}", error),
+                Either::This(ref backtrace)    => write!(f, "{:?}", backtrace),
+                Either::That(ref error)        => write!(f, "{:?}", error),
+                Either::Both(_, ref error)     => write!(f, "{:?}", error),
             }
         }
     }
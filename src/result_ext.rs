@@ -0,0 +1,62 @@
+use core::fmt::Display;
+
+use Fail;
+use context::Context;
+
+/// Extension methods for `Result`.
+pub trait ResultExt<T, E> {
+    /// Wraps the error in a context, annotating it with a human-readable,
+    /// user-facing explanation of what was being attempted.
+    ///
+    /// The context value is built eagerly; use [`with_context`] to defer that
+    /// work to the error path.
+    ///
+    /// [`with_context`]: #tymethod.with_context
+    fn context<D>(self, context: D) -> Result<T, Context<D>>
+        where D: Display + Send + Sync + 'static;
+
+    /// Wraps the error in a context produced lazily by `f`.
+    ///
+    /// The closure is only invoked when `self` is `Err`, so the context `D` is
+    /// never built on the success path. This avoids formatting and allocation in
+    /// hot paths such as `result.with_context(|| format!("reading {}", path))?`.
+    fn with_context<F, D>(self, f: F) -> Result<T, Context<D>>
+        where F: FnOnce() -> D,
+              D: Display + Send + Sync + 'static;
+}
+
+with_std! {
+    use Error;
+
+    impl<T, E> ResultExt<T, E> for Result<T, E> where E: Into<Error> {
+        fn context<D>(self, context: D) -> Result<T, Context<D>>
+            where D: Display + Send + Sync + 'static
+        {
+            self.map_err(|failure| Context::with_err(context, failure))
+        }
+
+        fn with_context<F, D>(self, f: F) -> Result<T, Context<D>>
+            where F: FnOnce() -> D,
+                  D: Display + Send + Sync + 'static
+        {
+            self.map_err(|failure| Context::with_err(f(), failure))
+        }
+    }
+}
+
+without_std! {
+    impl<T, E> ResultExt<T, E> for Result<T, E> where E: Fail {
+        fn context<D>(self, context: D) -> Result<T, Context<D>>
+            where D: Display + Send + Sync + 'static
+        {
+            self.map_err(|failure| Context::with_err(context, failure))
+        }
+
+        fn with_context<F, D>(self, f: F) -> Result<T, Context<D>>
+            where F: FnOnce() -> D,
+                  D: Display + Send + Sync + 'static
+        {
+            self.map_err(|failure| Context::with_err(f(), failure))
+        }
+    }
+}